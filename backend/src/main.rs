@@ -1,23 +1,29 @@
 use std::{
-    collections::HashMap,
-    sync::{Arc, LazyLock},
+    collections::{HashMap, HashSet, VecDeque},
+    path::PathBuf,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
 };
 
 use axum::{
     Router,
-    extract::{Path, Query, State},
+    extract::{MatchedPath, Path, Query, Request, State},
     http::StatusCode,
-    response::Json,
+    middleware::{self, Next},
+    response::{IntoResponse, Json, Response},
     routing::{get, post},
 };
-use chrono::Datelike;
-use futures::TryStreamExt;
+use chrono::{Datelike, Utc};
+use futures::{StreamExt, TryStreamExt};
+use metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
 use neo4rs::{
-    BoltNull, Config, ConfigBuilder, EndNodeId, Graph, Node, Relation, StartNodeId, query,
+    BoltNull, Config, ConfigBuilder, EndNodeId, Graph, Node, Path as GraphPath, Relation,
+    StartNodeId, query,
 };
 use reqwest::{Client, Method, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
-use serde_json::{Number, Value};
+use serde_json::{Number, Value, json};
 use tokio::sync::Semaphore;
 use tower_http::cors;
 
@@ -28,12 +34,142 @@ struct Tokens {
     neo4j_password: String,
 }
 
+/// Everything a handler can fail with. Each variant maps to a single HTTP
+/// status in [`IntoResponse`] so the frontend gets an actionable `{ error,
+/// detail }` body instead of a dropped connection from a panicking task.
+#[derive(thiserror::Error, Debug)]
+enum AppError {
+    #[error("upstream TMDB request failed")]
+    Upstream(#[from] reqwest::Error),
+    #[error("unexpected TMDB payload: {0}")]
+    UnexpectedPayload(String),
+    #[error("database error")]
+    Database(#[from] neo4rs::Error),
+    #[error("not found")]
+    NotFound,
+    #[error("{0}")]
+    NoPath(String),
+}
+
+impl AppError {
+    /// The HTTP status each failure surfaces to the client.
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::Upstream(_) => StatusCode::BAD_GATEWAY,
+            AppError::UnexpectedPayload(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Database(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::NotFound => StatusCode::NOT_FOUND,
+            AppError::NoPath(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    /// A stable machine-readable slug for the variant, used as the `error` field
+    /// so clients can branch without parsing `detail`.
+    fn kind(&self) -> &'static str {
+        match self {
+            AppError::Upstream(_) => "upstream_error",
+            AppError::UnexpectedPayload(_) => "unexpected_payload",
+            AppError::Database(_) => "database_error",
+            AppError::NotFound => "not_found",
+            AppError::NoPath(_) => "no_path",
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let body = Json(json!({
+            "error": self.kind(),
+            "detail": self.to_string(),
+        }));
+
+        (self.status_code(), body).into_response()
+    }
+}
+
+/// Reads a required field from a TMDB object as a string, reporting which field
+/// was missing or mistyped rather than panicking on odd payloads.
+fn payload_str(value: &Value, field: &str) -> Result<String, AppError> {
+    value
+        .get(field)
+        .and_then(|field| field.as_str())
+        .map(|field| field.to_string())
+        .ok_or_else(|| AppError::UnexpectedPayload(format!("expected string field `{field}`")))
+}
+
+/// Reads a required unsigned-integer field from a TMDB object.
+fn payload_u64(value: &Value, field: &str) -> Result<u64, AppError> {
+    value
+        .get(field)
+        .and_then(|field| field.as_u64())
+        .ok_or_else(|| AppError::UnexpectedPayload(format!("expected integer field `{field}`")))
+}
+
+/// Reads a required floating-point field from a TMDB object.
+fn payload_f64(value: &Value, field: &str) -> Result<f64, AppError> {
+    value
+        .get(field)
+        .and_then(|field| field.as_f64())
+        .ok_or_else(|| AppError::UnexpectedPayload(format!("expected number field `{field}`")))
+}
+
+/// Reads a required array field from a TMDB object.
+fn payload_array<'a>(value: &'a Value, field: &str) -> Result<&'a Vec<Value>, AppError> {
+    value
+        .get(field)
+        .and_then(|field| field.as_array())
+        .ok_or_else(|| AppError::UnexpectedPayload(format!("expected array field `{field}`")))
+}
+
 #[derive(Clone)]
 struct AppState {
     graph: Graph,
     max_connections: usize,
     tokens: Tokens,
     api_client: Client,
+    prometheus: PrometheusHandle,
+    cache: Arc<Mutex<ResponseCache>>,
+    cache_path: PathBuf,
+    cache_ttl_seconds: i64,
+    cache_max_entries: usize,
+    max_retries: u32,
+    retry_base_delay: Duration,
+}
+
+/// A single memoized TMDB response together with the instant it was stored, so
+/// expiry can be decided lazily on lookup.
+#[derive(Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    value: Value,
+    inserted_at: i64,
+}
+
+/// On-disk-backed memoization of raw TMDB JSON responses keyed by URL + query.
+/// Flushed to `castnet_cache.json` periodically and reloaded on startup.
+#[derive(Default, Serialize, Deserialize)]
+struct ResponseCache {
+    entries: HashMap<String, CacheEntry>,
+    #[serde(skip)]
+    writes_since_flush: u32,
+}
+
+/// Number of writes between disk flushes, so a burst of `add_film` inserts does
+/// not rewrite the whole file on every cached response.
+const CACHE_FLUSH_INTERVAL: u32 = 16;
+
+/// Builds the cache key for a request from its URL and query parameters. The
+/// parameters are sorted so that equivalent requests hash identically.
+fn cache_key(url: &str, query: &[(&str, &str)]) -> String {
+    let mut params = query.to_vec();
+    params.sort();
+
+    let query_string = params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{url}?{query_string}")
 }
 
 impl AppState {
@@ -46,6 +182,14 @@ impl AppState {
 
         let api_client = Client::new();
 
+        let prometheus = PrometheusBuilder::new().install_recorder().unwrap();
+
+        let cache_path = PathBuf::from("castnet_cache.json");
+        let cache = std::fs::read_to_string(&cache_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+
         let graph = create_graph(&tokens).await;
 
         Self {
@@ -53,6 +197,13 @@ impl AppState {
             max_connections: 16, // Same as neo4rs, but this value is inaccessible
             tokens,
             api_client,
+            prometheus,
+            cache: Arc::new(Mutex::new(cache)),
+            cache_path,
+            cache_ttl_seconds: 60 * 60 * 24, // One day
+            cache_max_entries: 4096,
+            max_retries: 5,
+            retry_base_delay: Duration::from_millis(500),
         }
     }
 
@@ -65,6 +216,218 @@ impl AppState {
             )
             .header("accept", "application/json")
     }
+
+    /// Issues a GET against TMDB, returning the parsed JSON body. Responses are
+    /// memoized by URL + query: a fresh entry is served from the cache without
+    /// touching the network, otherwise the upstream response is fetched, stored
+    /// and returned.
+    async fn api_get_cached(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Value, AppError> {
+        let key = cache_key(url, query);
+
+        if let Some(value) = self.cache_lookup(&key) {
+            counter!("api_tmdb_cache_total", "result" => "hit").increment(1);
+            return Ok(value);
+        }
+        counter!("api_tmdb_cache_total", "result" => "miss").increment(1);
+
+        let response = self
+            .send_with_retry(self.api_get_request(url).query(query))
+            .await;
+        record_tmdb_call(endpoint, &response);
+
+        // A non-2xx status is a failure, not data: surface it instead of
+        // parsing the error body as JSON and returning an empty result or a
+        // misleading 422. A 404 is a genuine "not found"; anything else non-2xx
+        // is an upstream failure (502). Both short-circuit before the cache, so
+        // only successes are memoized.
+        let response = response?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::NotFound);
+        }
+        let response = response.error_for_status()?;
+        let value = response.json::<Value>().await?;
+
+        self.cache_store(key, value.clone());
+
+        Ok(value)
+    }
+
+    /// Sends an idempotent GET, retrying transient failures (HTTP 429 and 5xx,
+    /// plus connection errors) up to `max_retries` times. Backoff is
+    /// exponential with jitter, but a `Retry-After` / `X-RateLimit-Reset`
+    /// header on a throttled response overrides it so we sleep exactly as long
+    /// as TMDB asks.
+    async fn send_with_retry(&self, builder: RequestBuilder) -> Result<Response, reqwest::Error> {
+        let mut attempt = 0;
+
+        loop {
+            let request = builder
+                .try_clone()
+                .expect("retryable GET requests must be cloneable");
+            let response = request.send().await;
+
+            let retry = match &response {
+                Ok(response) => is_retryable_status(response.status()),
+                Err(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+            };
+
+            if !retry || attempt >= self.max_retries {
+                return response;
+            }
+
+            let delay = match &response {
+                Ok(response) => retry_after(response)
+                    .unwrap_or_else(|| backoff_delay(self.retry_base_delay, attempt)),
+                Err(_) => backoff_delay(self.retry_base_delay, attempt),
+            };
+
+            counter!("api_tmdb_retries_total").increment(1);
+            tokio::time::sleep(delay).await;
+
+            attempt += 1;
+        }
+    }
+
+    /// Returns a cached response if one is present and has not outlived the TTL.
+    fn cache_lookup(&self, key: &str) -> Option<Value> {
+        let cache = self.cache.lock().unwrap();
+        let entry = cache.entries.get(key)?;
+
+        if Utc::now().timestamp() - entry.inserted_at > self.cache_ttl_seconds {
+            return None;
+        }
+
+        Some(entry.value.clone())
+    }
+
+    /// Inserts a fresh response, evicting the oldest entries once the size bound
+    /// is exceeded and flushing to disk every `CACHE_FLUSH_INTERVAL` writes.
+    fn cache_store(&self, key: String, value: Value) {
+        let mut cache = self.cache.lock().unwrap();
+
+        cache.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Utc::now().timestamp(),
+            },
+        );
+
+        while cache.entries.len() > self.cache_max_entries {
+            if let Some(oldest) = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.inserted_at)
+                .map(|(key, _)| key.clone())
+            {
+                cache.entries.remove(&oldest);
+            } else {
+                break;
+            }
+        }
+
+        cache.writes_since_flush += 1;
+        let serialized = if cache.writes_since_flush >= CACHE_FLUSH_INTERVAL {
+            cache.writes_since_flush = 0;
+            serde_json::to_string(&*cache).ok()
+        } else {
+            None
+        };
+
+        // Drop the lock before touching the disk: a blocking `fs::write` while
+        // holding it would stall the tokio worker and serialize every other
+        // handler's cache access for the duration of the flush.
+        drop(cache);
+        if let Some(serialized) = serialized {
+            let _ = std::fs::write(&self.cache_path, serialized);
+        }
+    }
+}
+
+/// Whether a status code represents a transient failure worth retrying: TMDB
+/// rate limiting (429) and any upstream server error (5xx).
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Exponential backoff (`base * 2^attempt`) with up to `base` of added jitter to
+/// spread out concurrent retries from a large `add_film` fan-out.
+fn backoff_delay(base: Duration, attempt: u32) -> Duration {
+    let exponential = base.saturating_mul(1u32 << attempt.min(16));
+    let jitter = Duration::from_millis((Utc::now().timestamp_subsec_nanos() as u64) % (base.as_millis() as u64 + 1));
+
+    exponential + jitter
+}
+
+/// Extracts the delay TMDB asks us to wait from a throttled response, honoring
+/// `Retry-After` (seconds) first and falling back to `X-RateLimit-Reset` (a
+/// unix timestamp) if present.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let headers = response.headers();
+
+    if let Some(seconds) = headers
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+    {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let reset = headers
+        .get("x-ratelimit-reset")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<i64>().ok())?;
+
+    let seconds = (reset - Utc::now().timestamp()).max(0) as u64;
+    Some(Duration::from_secs(seconds))
+}
+
+/// Records the outcome of an upstream TMDB request, bucketed by endpoint and
+/// HTTP status, so error rates are visible on `/metrics`.
+fn record_tmdb_call(endpoint: &'static str, response: &Result<Response, reqwest::Error>) {
+    let status = match response {
+        Ok(response) => response.status().as_u16().to_string(),
+        Err(err) => err
+            .status()
+            .map_or_else(|| "error".to_string(), |status| status.as_u16().to_string()),
+    };
+
+    counter!("api_tmdb_requests_total", "endpoint" => endpoint, "status" => status).increment(1);
+}
+
+/// Records every request against the per-route counter and latency histogram.
+/// Running as a layer rather than per-handler means error paths — which exit
+/// early via `?` and [`AppError`] — are counted too, instead of only the
+/// success branch that reaches an explicit recording call. `result` is `"ok"`
+/// for 2xx/3xx responses and `"error"` otherwise.
+async fn track_metrics(request: Request, next: Next) -> Response {
+    let started = Instant::now();
+    // Prefer the matched route template (`/graph/add/{film_id}`) over the raw
+    // path so ids do not explode the label cardinality.
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_owned())
+        .unwrap_or_else(|| request.uri().path().to_owned());
+
+    let response = next.run(request).await;
+
+    let result = if response.status().is_success() || response.status().is_redirection() {
+        "ok"
+    } else {
+        "error"
+    };
+
+    counter!("api_http_requests_total", "route" => route.clone(), "result" => result).increment(1);
+    histogram!("api_http_request_duration_seconds", "route" => route)
+        .record(started.elapsed().as_secs_f64());
+
+    response
 }
 
 #[derive(Serialize)]
@@ -89,6 +452,45 @@ struct Film {
     title: String,
     year: Option<i32>,
     popularity: f64,
+    /// Raw TMDB `watch/providers` `results` map, serialized as JSON so the
+    /// frontend can render streaming badges without a second request. `None`
+    /// for films surfaced by search that have not yet been ingested.
+    providers: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Provider {
+    id: u64,
+    name: String,
+    logo_path: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ProviderAvailability {
+    region: String,
+    flatrate: Vec<Provider>,
+    rent: Vec<Provider>,
+    buy: Vec<Provider>,
+}
+
+/// Maps a TMDB provider list (the `flatrate` / `rent` / `buy` arrays of a
+/// region block) into our serializable [`Provider`]s, skipping malformed rows.
+fn parse_providers(value: &Value) -> Vec<Provider> {
+    value
+        .as_array()
+        .unwrap_or(&vec![])
+        .iter()
+        .filter_map(|provider| {
+            Some(Provider {
+                id: provider.get("provider_id")?.as_u64()?,
+                name: provider.get("provider_name")?.as_str()?.to_string(),
+                logo_path: provider
+                    .get("logo_path")
+                    .and_then(|path| path.as_str())
+                    .map(|path| path.to_string()),
+            })
+        })
+        .collect()
 }
 
 async fn create_graph(tokens: &Tokens) -> Graph {
@@ -137,6 +539,11 @@ async fn main() {
         .route("/search/film", get(search_film))
         .route("/graph", get(get_graph))
         .route("/graph/add/{film_id}", post(add_film))
+        .route("/film/{film_id}/providers", get(get_film_providers))
+        .route("/path/{actor_id_a}/{actor_id_b}", get(shortest_path))
+        .route("/graph/expand/{actor_id}", post(expand_graph))
+        .route("/metrics", get(metrics))
+        .route_layer(middleware::from_fn(track_metrics))
         .layer(cors)
         .with_state(state);
 
@@ -148,17 +555,20 @@ async fn main() {
 async fn search_film(
     Query(params): Query<HashMap<String, String>>,
     State(state): State<AppState>,
-) -> Result<Json<Vec<Film>>, StatusCode> {
-    let title_query = params.get("title").ok_or(StatusCode::BAD_REQUEST)?;
+) -> Result<Json<Vec<Film>>, AppError> {
+    let title_query = params.get("title").ok_or_else(|| {
+        AppError::UnexpectedPayload("missing `title` query parameter".to_string())
+    })?;
 
-    let film_list_response = state
-        .api_get_request("https://api.themoviedb.org/3/search/movie")
-        .query(&[("query", &title_query)])
-        .send()
-        .await
-        .map_err(|err| err.status().unwrap())?;
+    let film_list_json = state
+        .api_get_cached(
+            "search/movie",
+            "https://api.themoviedb.org/3/search/movie",
+            &[("query", title_query.as_str())],
+        )
+        .await?;
 
-    let mut results = film_list_response.json::<Value>().await.unwrap()["results"]
+    let mut results = film_list_json["results"]
         .as_array()
         .unwrap_or(&vec![])
         .iter()
@@ -174,6 +584,7 @@ async fn search_film(
                 title: film.get("title")?.as_str()?.to_string(),
                 year,
                 popularity: film.get("popularity")?.as_f64()?,
+                providers: None,
             })
         })
         .collect::<Vec<_>>();
@@ -186,62 +597,106 @@ async fn search_film(
     Ok(Json(results))
 }
 
-async fn add_film(Path(film_id): Path<u64>, State(state): State<AppState>) {
-    let film_response = state
-        .api_get_request(format!("https://api.themoviedb.org/3/movie/{film_id}").as_str())
-        .send()
-        .await
-        .unwrap();
+#[axum::debug_handler]
+async fn metrics(State(state): State<AppState>) -> String {
+    state.prometheus.render()
+}
+
+#[axum::debug_handler]
+async fn add_film(
+    Path(film_id): Path<u64>,
+    State(state): State<AppState>,
+) -> Result<(), AppError> {
+    let semaphore = Arc::new(Semaphore::new(state.max_connections));
+    ingest_film(&state, film_id, semaphore).await?;
 
-    let film_json = film_response.json::<Value>().await.unwrap();
+    Ok(())
+}
 
-    let title = film_json["title"].as_str().unwrap().to_string();
-    let film_popularity = film_json["popularity"].as_f64().unwrap();
-    let year = film_json["release_date"]
-        .as_str()
+/// Fetches a film, its watch providers and its cast from TMDB and merges the
+/// actor / film / role graph into Neo4j, returning the ids of the actors it
+/// ingested. Shared by the single-add route and the recursive expander, both of
+/// which pass in a `semaphore` bounding concurrent Neo4j writes.
+async fn ingest_film(
+    state: &AppState,
+    film_id: u64,
+    semaphore: Arc<Semaphore>,
+) -> Result<Vec<u64>, AppError> {
+    let film_json = state
+        .api_get_cached(
+            "movie",
+            format!("https://api.themoviedb.org/3/movie/{film_id}").as_str(),
+            &[],
+        )
+        .await?;
+
+    let title = payload_str(&film_json, "title")?;
+    let film_popularity = payload_f64(&film_json, "popularity")?;
+    let year = film_json
+        .get("release_date")
+        .and_then(|date| date.as_str())
         .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
         .map(|parsed_date| parsed_date.year());
 
-    let cast_response = state
-        .api_get_request(format!("https://api.themoviedb.org/3/movie/{film_id}/credits").as_str())
-        .send()
-        .await
-        .unwrap();
+    let providers_json = state
+        .api_get_cached(
+            "movie/watch/providers",
+            format!("https://api.themoviedb.org/3/movie/{film_id}/watch/providers").as_str(),
+            &[],
+        )
+        .await?;
+    // Persist the raw per-region `results` map so `get_graph` and the
+    // `/film/{id}/providers` route can serve badges without re-querying TMDB.
+    let providers = serde_json::to_string(&providers_json["results"]).ok();
 
-    let cast_json = cast_response.json::<Value>().await.unwrap();
+    let cast_json = state
+        .api_get_cached(
+            "movie/credits",
+            format!("https://api.themoviedb.org/3/movie/{film_id}/credits").as_str(),
+            &[],
+        )
+        .await?;
 
-    let cast_list = cast_json["cast"].as_array().unwrap();
+    let cast_list = payload_array(&cast_json, "cast")?;
 
     let mut create_actor_handles = Vec::with_capacity(cast_list.len());
-    let semaphore = Arc::new(Semaphore::new(state.max_connections));
+    let mut ingested_actor_ids = Vec::with_capacity(cast_list.len());
 
     const MIN_POPULARITY: f64 = 0.8;
+    // Skip malformed cast rows rather than failing the whole ingest: a single
+    // actor missing the fields we filter on should not sink the film.
     let actors = cast_list
-        .into_iter()
-        .filter(|actor| actor.get("known_for_department").unwrap().as_str() == Some("Acting"))
-        .filter(|actor| actor.get("popularity").unwrap().as_f64().unwrap() > MIN_POPULARITY)
-        .filter(|actor| actor.get("adult").unwrap().as_bool().unwrap_or(false) == false);
+        .iter()
+        .filter(|actor| {
+            actor.get("known_for_department").and_then(|value| value.as_str()) == Some("Acting")
+        })
+        .filter(|actor| {
+            actor
+                .get("popularity")
+                .and_then(|value| value.as_f64())
+                .unwrap_or(0.0)
+                > MIN_POPULARITY
+        })
+        .filter(|actor| {
+            !actor
+                .get("adult")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false)
+        });
 
     for actor in actors {
         let graph = state.graph.clone();
-        let actor_id = actor.get("id").unwrap().as_i64().unwrap();
-        let actor_name = actor.get("name").unwrap().as_str().unwrap().to_string();
-        let actor_popularity = actor.get("popularity").unwrap().as_f64().unwrap();
-        let character = actor
-            .get("character")
-            .unwrap()
-            .as_str()
-            .unwrap()
-            .to_string();
-        let role_id = actor
-            .get("credit_id")
-            .unwrap()
-            .as_str()
-            .unwrap()
-            .to_string();
+        let actor_id = payload_u64(actor, "id")?;
+        ingested_actor_ids.push(actor_id);
+        let actor_id = actor_id as i64;
+        let actor_name = payload_str(actor, "name")?;
+        let actor_popularity = payload_f64(actor, "popularity")?;
+        let character = payload_str(actor, "character")?;
+        let role_id = payload_str(actor, "credit_id")?;
 
         let title = title.clone();
         let year = year.clone();
+        let providers = providers.clone();
 
         let semaphore = semaphore.clone();
 
@@ -262,7 +717,8 @@ async fn add_film(Path(film_id): Path<u64>, State(state): State<AppState>) {
                 ON CREATE
                     SET f.title = $title,
                         f.popularity = $film_popularity,
-                        f.year = $year
+                        f.year = $year,
+                        f.providers = $providers
 
                 MERGE (a)-[r:ROLE {id: $role_id}]->(f)
                 ON CREATE
@@ -278,16 +734,153 @@ async fn add_film(Path(film_id): Path<u64>, State(state): State<AppState>) {
             .param("film_popularity", film_popularity)
             .param("character", character)
             .param("role_id", role_id)
-            .param("year", year);
+            .param("year", year)
+            .param("providers", providers);
 
-            graph.run(create_actor_query).await.unwrap();
+            graph.run(create_actor_query).await
         });
         create_actor_handles.push(handle);
     }
 
-    futures::future::join_all(create_actor_handles).await;
+    // Surface the first Neo4j write failure instead of panicking the request
+    // task; a join error means the write task itself panicked, which is a bug.
+    for result in futures::future::join_all(create_actor_handles).await {
+        result.expect("neo4j write task panicked")?;
+    }
 
     println!("Added film \"{title}\" to graph.");
+
+    Ok(ingested_actor_ids)
+}
+
+#[derive(Serialize)]
+struct ExpandResponse {
+    actors_expanded: usize,
+    films_ingested: usize,
+}
+
+#[axum::debug_handler]
+async fn expand_graph(
+    Path(actor_id): Path<u64>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<AppState>,
+) -> Result<Json<ExpandResponse>, AppError> {
+    let depth: u32 = params
+        .get("depth")
+        .and_then(|depth| depth.parse().ok())
+        .unwrap_or(1);
+
+    // A visited set over both actors and films keeps the crawl from
+    // reprocessing a neighbourhood that connected-component cycles would
+    // otherwise revisit indefinitely.
+    let mut visited_actors = HashSet::new();
+    let mut visited_films = HashSet::new();
+    let mut films_ingested = 0usize;
+    let mut films_failed = 0usize;
+    let mut queue: VecDeque<(u64, u32)> = VecDeque::new();
+    queue.push_back((actor_id, depth));
+
+    // Shared across the whole crawl so a deep expansion respects the same
+    // `max_connections` write bound a single `add_film` does.
+    let semaphore = Arc::new(Semaphore::new(state.max_connections));
+
+    while let Some((current_actor, remaining)) = queue.pop_front() {
+        if !visited_actors.insert(current_actor) {
+            continue;
+        }
+
+        let credits_json = state
+            .api_get_cached(
+                "person/movie_credits",
+                format!("https://api.themoviedb.org/3/person/{current_actor}/movie_credits")
+                    .as_str(),
+                &[],
+            )
+            .await?;
+
+        let films = credits_json["cast"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default();
+
+        for film in films {
+            let Some(film_id) = film.get("id").and_then(|id| id.as_u64()) else {
+                continue;
+            };
+            if !visited_films.insert(film_id) {
+                continue;
+            }
+
+            // A single malformed film (bad payload, upstream hiccup) should skip
+            // and count, not abort a multi-hundred-film crawl — the same
+            // resilience `ingest_film` already affords individual cast rows.
+            let discovered = match ingest_film(&state, film_id, semaphore.clone()).await {
+                Ok(discovered) => discovered,
+                Err(err) => {
+                    films_failed += 1;
+                    println!("Skipped film {film_id} during expansion: {err}");
+                    continue;
+                }
+            };
+            films_ingested += 1;
+
+            // Only enqueue newly discovered actors while there is depth left to
+            // crawl; at `remaining == 0` we ingest the films but stop fanning out.
+            if remaining > 0 {
+                for discovered_actor in discovered {
+                    if !visited_actors.contains(&discovered_actor) {
+                        queue.push_back((discovered_actor, remaining - 1));
+                    }
+                }
+            }
+        }
+    }
+
+    println!(
+        "Expanded graph from actor {actor_id}, actors: {}, films: {films_ingested}, skipped: {films_failed}",
+        visited_actors.len(),
+    );
+
+    Ok(Json(ExpandResponse {
+        actors_expanded: visited_actors.len(),
+        films_ingested,
+    }))
+}
+
+#[axum::debug_handler]
+async fn get_film_providers(
+    Path(film_id): Path<u64>,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<AppState>,
+) -> Result<Json<ProviderAvailability>, AppError> {
+    let region = params
+        .get("region")
+        .map(|region| region.to_uppercase())
+        .unwrap_or_else(|| "US".to_string());
+
+    let providers_json = state
+        .api_get_cached(
+            "movie/watch/providers",
+            format!("https://api.themoviedb.org/3/movie/{film_id}/watch/providers").as_str(),
+            &[],
+        )
+        .await?;
+
+    // TMDB answers an unknown film with a 404 body that carries no `results`
+    // map; surface that as a 404 rather than an empty availability block.
+    let results = providers_json
+        .get("results")
+        .ok_or(AppError::NotFound)?;
+    let region_block = &results[&region];
+
+    let availability = ProviderAvailability {
+        flatrate: parse_providers(&region_block["flatrate"]),
+        rent: parse_providers(&region_block["rent"]),
+        buy: parse_providers(&region_block["buy"]),
+        region,
+    };
+
+    Ok(Json(availability))
 }
 
 #[derive(Serialize)]
@@ -298,66 +891,64 @@ struct GraphResponse {
 }
 
 #[axum::debug_handler]
-async fn get_graph(State(state): State<AppState>) -> Json<GraphResponse> {
+async fn get_graph(State(state): State<AppState>) -> Result<Json<GraphResponse>, AppError> {
     let actors = state
         .graph
         .execute(query("MATCH (a:Actor) RETURN a;"))
-        .await
-        .unwrap()
+        .await?
         .into_stream()
-        .map_ok(|row| {
-            let actor: Node = row.get("a").unwrap();
-            Actor {
-                id: actor.get("id").unwrap(),
-                name: actor.get("name").unwrap(),
-                popularity: actor.get("popularity").unwrap(),
-                features: actor.get("features").unwrap(),
-            }
+        .map(|row| -> Result<Actor, neo4rs::Error> {
+            let row = row?;
+            let actor: Node = row.get("a")?;
+            Ok(Actor {
+                id: actor.get("id")?,
+                name: actor.get("name")?,
+                popularity: actor.get("popularity")?,
+                features: actor.get("features")?,
+            })
         })
         .try_collect::<Vec<Actor>>()
-        .await
-        .unwrap();
+        .await?;
 
     let films = state
         .graph
         .execute(query("MATCH (f:Film) RETURN f;"))
-        .await
-        .unwrap()
+        .await?
         .into_stream()
-        .map_ok(|row| {
-            let film: Node = row.get("f").unwrap();
-            Film {
-                id: film.get("id").unwrap(),
-                title: film.get("title").unwrap(),
-                year: film.get("year").unwrap(),
-                popularity: film.get("popularity").unwrap(),
-            }
+        .map(|row| -> Result<Film, neo4rs::Error> {
+            let row = row?;
+            let film: Node = row.get("f")?;
+            Ok(Film {
+                id: film.get("id")?,
+                title: film.get("title")?,
+                year: film.get("year")?,
+                popularity: film.get("popularity")?,
+                providers: film.get("providers").ok(),
+            })
         })
         .try_collect::<Vec<Film>>()
-        .await
-        .unwrap();
+        .await?;
 
     let roles = state
         .graph
         .execute(query("MATCH (a:Actor)-[r:ROLE]->(f:Film) RETURN a, r, f;"))
-        .await
-        .unwrap()
+        .await?
         .into_stream()
-        .map_ok(|row| {
-            let actor: Node = row.get("a").unwrap();
-            let role: Relation = row.get("r").unwrap();
-            let film: Node = row.get("f").unwrap();
-
-            Role {
-                id: role.get("id").unwrap(),
-                actor_id: actor.get("id").unwrap(),
-                film_id: film.get("id").unwrap(),
-                character: role.get("character").unwrap(),
-            }
+        .map(|row| -> Result<Role, neo4rs::Error> {
+            let row = row?;
+            let actor: Node = row.get("a")?;
+            let role: Relation = row.get("r")?;
+            let film: Node = row.get("f")?;
+
+            Ok(Role {
+                id: role.get("id")?,
+                actor_id: actor.get("id")?,
+                film_id: film.get("id")?,
+                character: role.get("character")?,
+            })
         })
         .try_collect::<Vec<Role>>()
-        .await
-        .unwrap();
+        .await?;
 
     println!(
         "Got graph, actors: {}, films: {}, roles: {}",
@@ -366,9 +957,165 @@ async fn get_graph(State(state): State<AppState>) -> Json<GraphResponse> {
         roles.len()
     );
 
-    Json(GraphResponse {
+    gauge!("graph_actors").set(actors.len() as f64);
+    gauge!("graph_films").set(films.len() as f64);
+    gauge!("graph_roles").set(roles.len() as f64);
+
+    Ok(Json(GraphResponse {
         actors,
         films,
         roles,
+    }))
+}
+
+/// The chain of films connecting two actors, returned by [`shortest_path`]. The
+/// `actors`, `films` and `roles` reuse the graph response structs so the
+/// frontend can render the path with the same components it draws the graph
+/// with; `degrees` is the number of shared-film hops (the classic Bacon number).
+#[derive(Serialize)]
+struct PathResponse {
+    actors: Vec<Actor>,
+    films: Vec<Film>,
+    roles: Vec<Role>,
+    degrees: usize,
+}
+
+/// Maps a path node into an [`Actor`]. Only called on nodes carrying the `Actor`
+/// label, so the property reads mirror those in [`get_graph`].
+fn actor_from_node(node: &Node) -> Result<Actor, neo4rs::Error> {
+    Ok(Actor {
+        id: node.get("id")?,
+        name: node.get("name")?,
+        popularity: node.get("popularity")?,
+        features: node.get("features")?,
     })
 }
+
+/// Maps a path node into a [`Film`]. Only called on nodes carrying the `Film`
+/// label, so the property reads mirror those in [`get_graph`].
+fn film_from_node(node: &Node) -> Result<Film, neo4rs::Error> {
+    Ok(Film {
+        id: node.get("id")?,
+        title: node.get("title")?,
+        year: node.get("year")?,
+        popularity: node.get("popularity")?,
+        providers: node.get("providers").ok(),
+    })
+}
+
+#[axum::debug_handler]
+async fn shortest_path(
+    Path((actor_id_a, actor_id_b)): Path<(u64, u64)>,
+    State(state): State<AppState>,
+) -> Result<Json<PathResponse>, AppError> {
+    // Both endpoints must exist before we attempt a traversal, otherwise an
+    // absent id is indistinguishable from "no path" to the client.
+    let requested: Vec<i64> = if actor_id_a == actor_id_b {
+        vec![actor_id_a as i64]
+    } else {
+        vec![actor_id_a as i64, actor_id_b as i64]
+    };
+
+    let present: i64 = state
+        .graph
+        .execute(
+            query("MATCH (a:Actor) WHERE a.id IN $ids RETURN count(a) AS present")
+                .param("ids", requested.clone()),
+        )
+        .await?
+        .next()
+        .await?
+        .map(|row| row.get("present"))
+        .transpose()?
+        .unwrap_or(0);
+
+    if (present as usize) < requested.len() {
+        return Err(AppError::NotFound);
+    }
+
+    // An actor is trivially connected to themselves; report an empty chain
+    // rather than a self-loop.
+    if actor_id_a == actor_id_b {
+        return Ok(Json(PathResponse {
+            actors: Vec::new(),
+            films: Vec::new(),
+            roles: Vec::new(),
+            degrees: 0,
+        }));
+    }
+
+    // Bound the traversal (`ROLE*..12`) so a disconnected pair cannot trigger a
+    // runaway search across the whole graph.
+    let row = state
+        .graph
+        .execute(
+            query(
+                "MATCH (a:Actor {id: $a}), (b:Actor {id: $b})
+                MATCH p = shortestPath((a)-[:ROLE*..12]-(b))
+                RETURN p",
+            )
+            .param("a", actor_id_a as i64)
+            .param("b", actor_id_b as i64),
+        )
+        .await?
+        .next()
+        .await?;
+
+    // No chain within the hop bound: the actors exist but are not connected,
+    // which is a well-formed-but-unsatisfiable query rather than a 404.
+    let Some(row) = row else {
+        return Err(AppError::NoPath(format!(
+            "no path between actors {actor_id_a} and {actor_id_b} within 12 hops"
+        )));
+    };
+
+    let path: GraphPath = row.get("p")?;
+    let nodes = path.nodes();
+    let relations = path.rels();
+
+    let mut actors = Vec::new();
+    let mut films = Vec::new();
+    for node in &nodes {
+        if node.labels().contains(&"Actor".to_string()) {
+            actors.push(actor_from_node(node)?);
+        } else {
+            films.push(film_from_node(node)?);
+        }
+    }
+
+    // Each relationship connects the two nodes straddling it in the sequence;
+    // the ROLE always points actor -> film regardless of traversal direction.
+    let roles = relations
+        .iter()
+        .enumerate()
+        .map(|(index, relation)| {
+            let left = &nodes[index];
+            let right = &nodes[index + 1];
+            let (actor, film) = if left.labels().contains(&"Actor".to_string()) {
+                (left, right)
+            } else {
+                (right, left)
+            };
+
+            Ok(Role {
+                id: relation.get("id")?,
+                actor_id: actor.get("id")?,
+                film_id: film.get("id")?,
+                character: relation.get("character")?,
+            })
+        })
+        .collect::<Result<Vec<Role>, neo4rs::Error>>()?;
+
+    let degrees = films.len();
+
+    println!(
+        "Found path between actors {actor_id_a} and {actor_id_b}, degrees: {degrees}"
+    );
+
+    Ok(Json(PathResponse {
+        actors,
+        films,
+        roles,
+        degrees,
+    }))
+}